@@ -1,5 +1,7 @@
 use anyhow::{Result, anyhow, bail};
 use std::collections::VecDeque;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Condvar, LockResult, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
@@ -26,23 +28,29 @@ struct Inner<T> {
     current_event: Mutex<Option<Event<T>>>, // 当前待发送事件（可覆盖）
     condvar: Condvar,                       // 条件变量，用于线程通知
     delay: Duration,                        // 发送延迟
-    active: Mutex<bool>,                    // 通道是否活跃
+    senders: AtomicUsize,                   // 存活的发送端数量
+    receivers: AtomicUsize,                 // 存活的接收端数量
 }
 
 impl<T> Sender<T> {
     /// 发送事件，如果已有事件在等待，则覆盖它
     pub fn send(&self, data: T) -> Result<()> {
+        self.send_at(data, Instant::now() + self.inner.delay)
+    }
+
+    /// 安排事件在绝对时间点 `at` 发送，如果已有事件在等待，则覆盖它。
+    /// 与 [`Sender::send`] 共用同一条工作线程调度路径
+    #[allow(dead_code)]
+    pub fn send_at(&self, data: T, at: Instant) -> Result<()> {
         let mut current_event = self.inner.current_event.lock().map_lock_err()?;
 
-        if !*self.inner.active.lock().map_lock_err()? {
+        if self.inner.receivers.load(Ordering::SeqCst) == 0 {
             bail!("Channel is closed");
         }
 
-        // 创建新事件，安排在延迟后发送
-        let scheduled_time = Instant::now() + self.inner.delay;
         *current_event = Some(Event {
             data,
-            scheduled_time,
+            scheduled_time: at,
         });
 
         // 通知工作线程检查新事件
@@ -55,7 +63,7 @@ impl<T> Sender<T> {
     pub fn send_immediate(&self, data: T) -> Result<()> {
         let mut queue = self.inner.queue.lock().map_lock_err()?;
 
-        if !*self.inner.active.lock().map_lock_err()? {
+        if self.inner.receivers.load(Ordering::SeqCst) == 0 {
             bail!("Channel is closed");
         }
 
@@ -79,9 +87,9 @@ impl<T> Receiver<T> {
     pub fn recv(&self) -> Result<T> {
         let mut queue = self.inner.queue.lock().map_lock_err()?;
 
-        // 等待队列中有事件或通道关闭
+        // 等待队列中有事件，或所有发送端已丢弃且队列耗尽
         while queue.is_empty() {
-            if !*self.inner.active.lock().map_lock_err()? {
+            if self.inner.senders.load(Ordering::SeqCst) == 0 {
                 bail!("Channel is closed");
             }
             queue = self.inner.condvar.wait(queue).map_lock_err()?;
@@ -101,14 +109,72 @@ impl<T> Receiver<T> {
 
         if let Some(event) = queue.pop_front() {
             Ok(event.data)
-        } else if !*self.inner.active.lock().map_lock_err()? {
+        } else if self.inner.senders.load(Ordering::SeqCst) == 0 {
             bail!("Channel is closed")
         } else {
             bail!("Channel is empty")
         }
     }
+
+    /// 接收事件，最多等待 `timeout`，超时返回 [`RecvTimeoutError::Timeout`]，
+    /// 通道关闭返回 [`RecvTimeoutError::Closed`]，不必在轮询和无限阻塞之间二选一
+    #[allow(dead_code)]
+    pub fn recv_timeout(&self, timeout: Duration) -> std::result::Result<T, RecvTimeoutError> {
+        let deadline = Instant::now() + timeout;
+        let mut queue = self
+            .inner
+            .queue
+            .lock()
+            .map_err(|_| RecvTimeoutError::Closed)?;
+
+        loop {
+            if let Some(event) = queue.pop_front() {
+                return Ok(event.data);
+            }
+
+            if self.inner.senders.load(Ordering::SeqCst) == 0 {
+                return Err(RecvTimeoutError::Closed);
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(RecvTimeoutError::Timeout);
+            }
+
+            let (new_queue, timeout_result) = self
+                .inner
+                .condvar
+                .wait_timeout(queue, deadline - now)
+                .map_err(|_| RecvTimeoutError::Closed)?;
+            queue = new_queue;
+
+            if timeout_result.timed_out() && queue.is_empty() {
+                return Err(RecvTimeoutError::Timeout);
+            }
+        }
+    }
 }
 
+/// [`Receiver::recv_timeout`] 的错误类型，区分超时与通道关闭两种情形
+#[derive(Debug, PartialEq, Eq)]
+pub enum RecvTimeoutError {
+    /// 等待超时，队列仍为空
+    Timeout,
+    /// 通道已关闭（所有发送端已丢弃且队列已耗尽）
+    Closed,
+}
+
+impl fmt::Display for RecvTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecvTimeoutError::Timeout => write!(f, "timed out waiting on channel"),
+            RecvTimeoutError::Closed => write!(f, "channel is closed"),
+        }
+    }
+}
+
+impl std::error::Error for RecvTimeoutError {}
+
 /// 创建新的延迟通道
 pub fn channel<T: Send + 'static>(delay: Duration) -> (Sender<T>, Receiver<T>) {
     let inner = Arc::new(Inner {
@@ -116,7 +182,8 @@ pub fn channel<T: Send + 'static>(delay: Duration) -> (Sender<T>, Receiver<T>) {
         current_event: Mutex::new(None),
         condvar: Condvar::new(),
         delay,
-        active: Mutex::new(true),
+        senders: AtomicUsize::new(1),
+        receivers: AtomicUsize::new(1),
     });
 
     let sender = Sender {
@@ -176,8 +243,9 @@ fn worker_thread<T: Send + 'static>(inner: Arc<Inner<T>>) {
             }
         };
 
-        // 检查通道是否已关闭
-        if !*inner.active.lock().unwrap() {
+        // 所有发送端已丢弃（不会再有新事件），或所有接收端已丢弃（没有人会收事件了），结束工作线程
+        if inner.senders.load(Ordering::SeqCst) == 0 || inner.receivers.load(Ordering::SeqCst) == 0
+        {
             break;
         }
 
@@ -197,32 +265,30 @@ fn worker_thread<T: Send + 'static>(inner: Arc<Inner<T>>) {
 
 impl<T> Clone for Sender<T> {
     fn clone(&self) -> Sender<T> {
+        self.inner.senders.fetch_add(1, Ordering::SeqCst);
         Sender {
             inner: self.inner.clone(),
         }
     }
 }
 
-impl<T> Drop for Inner<T> {
-    fn drop(&mut self) {
-        *self.active.lock().unwrap() = false;
-        self.condvar.notify_all(); // 唤醒所有等待的线程
-    }
-}
-
 impl<T> Drop for Sender<T> {
     fn drop(&mut self) {
-        // 发送端被丢弃时，关闭通道
-        *self.inner.active.lock().unwrap() = false;
-        self.inner.condvar.notify_all();
+        // 只有最后一个发送端被丢弃时，才关闭通道
+        if self.inner.senders.fetch_sub(1, Ordering::SeqCst) == 1 {
+            // 持有队列锁再通知，避免通知落在 recv 检查计数与进入等待之间而丢失
+            let _queue = self.inner.queue.lock();
+            self.inner.condvar.notify_all();
+        }
     }
 }
 
 impl<T> Drop for Receiver<T> {
     fn drop(&mut self) {
-        // 接收端被丢弃时，关闭通道
-        *self.inner.active.lock().unwrap() = false;
-        self.inner.condvar.notify_all();
+        // 只有最后一个接收端被丢弃时，才关闭通道
+        if self.inner.receivers.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.inner.condvar.notify_all();
+        }
     }
 }
 
@@ -328,4 +394,59 @@ mod tests {
         // 应该只收到event3, event4, event5
         assert_eq!(results, vec!["event3", "event4", "event5"]);
     }
+
+    #[test]
+    fn test_recv_timeout_expires_when_empty() {
+        let delay = Duration::from_millis(10);
+        let (_tx, rx) = channel::<&str>(delay);
+
+        let result = rx.recv_timeout(Duration::from_millis(30));
+        assert_eq!(result, Err(RecvTimeoutError::Timeout));
+    }
+
+    #[test]
+    fn test_recv_timeout_returns_closed_after_last_sender_drops() {
+        let delay = Duration::from_millis(10);
+        let (tx, rx) = channel::<&str>(delay);
+
+        drop(tx);
+        let result = rx.recv_timeout(Duration::from_millis(200));
+        assert_eq!(result, Err(RecvTimeoutError::Closed));
+    }
+
+    #[test]
+    fn test_send_at_schedules_absolute_deadline() {
+        let delay = Duration::from_millis(100);
+        let (tx, rx) = channel(delay);
+
+        let start = Instant::now();
+        tx.send_at("event", start + Duration::from_millis(30))
+            .unwrap();
+
+        let result = rx.recv().unwrap();
+        assert_eq!(result, "event");
+
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(30));
+        assert!(elapsed < Duration::from_millis(90));
+    }
+
+    #[test]
+    fn test_clone_sender_keeps_channel_open() {
+        let delay = Duration::from_millis(20);
+        let (tx, rx) = channel(delay);
+        let tx2 = tx.clone();
+
+        // 丢弃其中一个发送端的克隆，通道应保持开放
+        drop(tx2);
+
+        tx.send("still open").unwrap();
+        let result = rx.recv().unwrap();
+        assert_eq!(result, "still open");
+
+        // 丢弃最后一个发送端后，通道才关闭
+        drop(tx);
+        thread::sleep(Duration::from_millis(40));
+        assert!(rx.recv().is_err());
+    }
 }
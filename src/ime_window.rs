@@ -0,0 +1,67 @@
+use std::thread::{self, JoinHandle};
+
+use windows::Win32::System::Com::{
+    CLSCTX_INPROC_SERVER, CoCreateInstance, CoInitializeEx, CoUninitialize,
+};
+use windows::Win32::UI::TextServices::{
+    CLSID_TF_ThreadMgr, ITfActiveLanguageProfileNotifySink, ITfActiveLanguageProfileNotifySink_Impl,
+    ITfSource, ITfThreadMgr,
+};
+use windows::Win32::UI::WindowsAndMessaging::{DispatchMessageW, GetMessageW, MSG, TranslateMessage};
+use windows::core::{GUID, Interface, Result as WinResult, implement};
+
+use crate::monitor::Event;
+use crate::reactor::Handle;
+
+// WM_INPUTLANGCHANGE/WM_INPUTLANGCHANGEREQUEST 只会送到当前拥有键盘焦点的窗口，
+// 而这永远不是我们自己创建的（隐藏的）窗口，所以没有 AttachThreadInput /
+// SetWindowsHookEx 之类的桥接，一个仅消息窗口是收不到这些消息的。
+// TSF 的 ITfActiveLanguageProfileNotifySink 则是线程级别的通知：不管哪个窗口
+// 持有焦点，只要当前线程活跃的语言配置发生变化就会回调，这才是"权威事件"。
+#[implement(ITfActiveLanguageProfileNotifySink)]
+struct LanguageProfileSink {
+    handle: Handle<Event>,
+}
+
+impl ITfActiveLanguageProfileNotifySink_Impl for LanguageProfileSink_Impl {
+    fn OnActivated(
+        &self,
+        _clsid: *const GUID,
+        _guid_profile: *const GUID,
+        activated: windows::Win32::Foundation::BOOL,
+    ) -> WinResult<()> {
+        if activated.as_bool() {
+            self.handle.post(Event::MayChangeIME);
+        }
+        Ok(())
+    }
+}
+
+/// 注册 TSF 语言配置切换通知，在独立线程上运行消息泵以驱动回调，
+/// 每次输入法切换都会向 `handle` 投递 [`Event::MayChangeIME`]
+pub fn listen(handle: Handle<Event>) -> JoinHandle<()> {
+    thread::spawn(move || unsafe {
+        // TSF 的通知要求调用线程是一个 COM 单线程单元(STA)，并持续跑消息泵
+        CoInitializeEx(None, windows::Win32::System::Com::COINIT_APARTMENTTHREADED)
+            .expect("初始化 COM 失败");
+
+        let thread_mgr: ITfThreadMgr =
+            CoCreateInstance(&CLSID_TF_ThreadMgr, None, CLSCTX_INPROC_SERVER)
+                .expect("创建 TSF 线程管理器失败");
+        let source: ITfSource = thread_mgr.cast().expect("获取 ITfSource 失败");
+
+        let sink: ITfActiveLanguageProfileNotifySink = LanguageProfileSink { handle }.into();
+        let cookie = source
+            .AdviseSink(&ITfActiveLanguageProfileNotifySink::IID, &sink)
+            .expect("注册语言切换通知失败");
+
+        let mut msg = MSG::default();
+        while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+            let _ = TranslateMessage(&msg);
+            DispatchMessageW(&msg);
+        }
+
+        let _ = source.UnadviseSink(cookie);
+        CoUninitialize();
+    })
+}
@@ -5,7 +5,10 @@ use crate::monitor::Monitor;
 mod channel;
 mod cursor;
 mod ime;
+mod ime_window;
 mod monitor;
+mod reactor;
+mod timing_wheel;
 
 fn main() {
     let mut monitor = Monitor::new().expect("创建监听器失败");
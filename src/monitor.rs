@@ -1,14 +1,25 @@
 use euro_focus::subscribe_focus_changes;
-use rdev::{EventType, Key, listen};
 
-use std::sync::mpsc::{Receiver, Sender, channel};
+use std::sync::mpsc::Receiver;
 use std::thread::{self, JoinHandle};
-use std::time::Instant;
+use std::time::Duration;
 
 use crate::cursor::Cursor;
 use crate::ime::{IMEControl, InputMode};
-
-struct MayChangeIME;
+use crate::ime_window;
+use crate::reactor::{Handle, Reactor};
+use crate::timing_wheel::TimingWheel;
+
+/// 防抖延迟：这段时间内连续到来的事件只触发一次光标更新
+const DEBOUNCE_DELAY: Duration = Duration::from_millis(150);
+
+/// 反应器上流转的事件
+pub(crate) enum Event {
+    /// 输入法可能发生了切换，需要重新确认光标样式
+    MayChangeIME,
+    /// 防抖定时器到期，应当真正更新一次光标
+    CursorUpdate,
+}
 
 pub struct Monitor {
     ime: IMEControl,
@@ -24,20 +35,32 @@ impl Monitor {
     }
 
     pub fn run(&mut self) {
-        let (sender, receiver) = channel();
+        let reactor = Reactor::new();
 
-        let _keyboard_handle = Self::listen_keyboard(sender.clone());
-        let _window_handle = Self::listen_window(sender);
+        let _ime_window_handle = ime_window::listen(reactor.register());
+        let _window_handle = Self::listen_window(reactor.register());
 
         let _ = self.cursor.reset_cursor();
         self.set_cursor();
 
-        let (delay_sender, delay_receiver) = channel();
-        let _delay_send_handle = Self::delay_send(receiver, delay_sender);
-
-        while let Ok(_) = delay_receiver.recv() {
-            self.set_cursor();
-        }
+        let (wheel, wheel_events) = TimingWheel::new();
+        let _wheel_handle = Self::forward_wheel(wheel_events, reactor.register());
+
+        let mut timer_id: Option<u64> = None;
+        reactor.run(|event| match event {
+            Event::MayChangeIME => {
+                // 若旧定时器已经在这次事件之前触发过，refresh 会返回 false，
+                // 此时需要重新 add 一个，避免这次事件被悄悄丢弃
+                let refreshed = timer_id.is_some_and(|id| wheel.refresh(id));
+                if !refreshed {
+                    timer_id = Some(wheel.add(DEBOUNCE_DELAY, ()));
+                }
+            }
+            Event::CursorUpdate => {
+                timer_id = None;
+                self.set_cursor();
+            }
+        });
     }
 
     fn set_cursor(&mut self) {
@@ -50,54 +73,21 @@ impl Monitor {
         }
     }
 
-    fn delay_send(
-        receiver: Receiver<MayChangeIME>,
-        delay_sender: Sender<MayChangeIME>,
-    ) -> JoinHandle<()> {
+    fn listen_window(handle: Handle<Event>) -> JoinHandle<()> {
         thread::spawn(move || {
-            let mut last_time: Option<(Instant, MayChangeIME)> = None;
-            loop {
-                if let Some((l, _)) = last_time
-                    && l.elapsed().as_millis() > 150
-                    && let Some((_, e)) = last_time.take()
-                {
-                    let _ = delay_sender.send(e);
-                }
+            let receiver = subscribe_focus_changes().expect("启动窗口监听失败");
 
-                if let Ok(e) = receiver.try_recv() {
-                    last_time = Some((Instant::now(), e));
-                }
+            while let Ok(_) = receiver.recv() {
+                handle.post(Event::MayChangeIME);
             }
         })
     }
 
-    fn listen_keyboard(sender: Sender<MayChangeIME>) -> JoinHandle<()> {
+    /// 把时间轮到期的定时器转发为反应器事件
+    fn forward_wheel(receiver: Receiver<()>, handle: Handle<Event>) -> JoinHandle<()> {
         thread::spawn(move || {
-            listen(move |event| {
-                if let EventType::KeyRelease(key) = event.event_type
-                    && matches!(
-                        key,
-                        Key::ControlLeft
-                            | Key::ControlRight
-                            | Key::ShiftLeft
-                            | Key::ShiftRight
-                            | Key::MetaLeft
-                            | Key::MetaRight
-                    )
-                {
-                    let _ = sender.send(MayChangeIME);
-                }
-            })
-            .expect("启动键盘监听失败");
-        })
-    }
-
-    fn listen_window(sender: Sender<MayChangeIME>) -> JoinHandle<()> {
-        thread::spawn(move || {
-            let receiver = subscribe_focus_changes().expect("启动窗口监听失败");
-
-            while let Ok(_) = receiver.recv() {
-                let _ = sender.send(MayChangeIME);
+            while let Ok(()) = receiver.recv() {
+                handle.post(Event::CursorUpdate);
             }
         })
     }
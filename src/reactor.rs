@@ -0,0 +1,172 @@
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+
+struct Inner<E> {
+    queue: Mutex<VecDeque<E>>,
+    condvar: Condvar,
+    sources: AtomicUsize, // 存活的事件源句柄数量
+}
+
+/// 事件反应器：异构的事件源通过 [`Handle`] 把各自的事件投递到同一个队列，
+/// `run` 在单个循环中按到达顺序取出事件并交给处理函数分发，
+/// 新增一个触发源只需要 `register` 一个句柄即可。
+pub struct Reactor<E> {
+    inner: Arc<Inner<E>>,
+}
+
+/// 向反应器投递事件的句柄，每个事件源持有一个
+pub struct Handle<E> {
+    inner: Arc<Inner<E>>,
+}
+
+impl<E> Reactor<E> {
+    pub fn new() -> Self {
+        Reactor {
+            inner: Arc::new(Inner {
+                queue: Mutex::new(VecDeque::new()),
+                condvar: Condvar::new(),
+                sources: AtomicUsize::new(0),
+            }),
+        }
+    }
+
+    /// 注册一个新的事件源，返回向本反应器投递事件的句柄
+    pub fn register(&self) -> Handle<E> {
+        self.inner.sources.fetch_add(1, Ordering::SeqCst);
+        Handle {
+            inner: self.inner.clone(),
+        }
+    }
+
+    /// 阻塞分发事件，直到所有已注册的句柄都被丢弃且队列耗尽
+    pub fn run(&self, mut handler: impl FnMut(E)) {
+        loop {
+            let mut queue = self.inner.queue.lock().unwrap();
+
+            while queue.is_empty() {
+                if self.inner.sources.load(Ordering::SeqCst) == 0 {
+                    return;
+                }
+                queue = self.inner.condvar.wait(queue).unwrap();
+            }
+
+            let event = queue.pop_front().unwrap();
+            drop(queue);
+
+            handler(event);
+        }
+    }
+}
+
+impl<E> Handle<E> {
+    /// 投递一个事件
+    pub fn post(&self, event: E) {
+        self.inner.queue.lock().unwrap().push_back(event);
+        self.inner.condvar.notify_one();
+    }
+}
+
+impl<E> Clone for Handle<E> {
+    fn clone(&self) -> Self {
+        self.inner.sources.fetch_add(1, Ordering::SeqCst);
+        Handle {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<E> Drop for Handle<E> {
+    fn drop(&mut self) {
+        // 只有最后一个句柄被丢弃时，反应器才失去这一事件源
+        if self.inner.sources.fetch_sub(1, Ordering::SeqCst) == 1 {
+            // 持有队列锁再通知，避免通知落在 run 检查计数与进入等待之间而丢失
+            let _queue = self.inner.queue.lock();
+            self.inner.condvar.notify_all();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_dispatches_events_in_order() {
+        let reactor = Reactor::new();
+        let handle = reactor.register();
+
+        handle.post(1);
+        handle.post(2);
+        handle.post(3);
+        drop(handle);
+
+        let mut seen = Vec::new();
+        reactor.run(|event| seen.push(event));
+
+        assert_eq!(seen, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_run_exits_once_all_handles_drop_and_queue_drains() {
+        let reactor = Reactor::new();
+        let handle = reactor.register();
+
+        let poster = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            handle.post(());
+            // handle 在这里被丢弃
+        });
+
+        let mut count = 0;
+        reactor.run(|_| count += 1);
+        poster.join().unwrap();
+
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn test_run_waits_while_any_handle_survives() {
+        let reactor = Reactor::new();
+        let _keep_alive = reactor.register();
+        let handle = reactor.register();
+
+        let poster = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(30));
+            handle.post("late");
+        });
+
+        // 只要 _keep_alive 还活着，run 就不会因为队列暂时为空而返回，
+        // 而是会在收到这条延迟投递的事件后才处理它
+        let mut received = None;
+        let runner = thread::spawn(move || {
+            reactor.run(|event| {
+                received = Some(event);
+            });
+            received
+        });
+
+        poster.join().unwrap();
+        // _keep_alive 仍未丢弃，run 还在等待；显式丢弃后队列已空且无活跃句柄，应当退出
+        drop(_keep_alive);
+        assert_eq!(runner.join().unwrap(), Some("late"));
+    }
+
+    #[test]
+    fn test_clone_keeps_reactor_alive() {
+        let reactor = Reactor::new();
+        let handle = reactor.register();
+        let cloned = handle.clone();
+
+        drop(handle);
+        cloned.post("still alive");
+        drop(cloned);
+
+        let mut seen = Vec::new();
+        reactor.run(|event| seen.push(event));
+
+        assert_eq!(seen, vec!["still alive"]);
+    }
+}
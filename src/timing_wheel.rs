@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// 轮盘槽位数量
+const SLOT_COUNT: usize = 512;
+/// 单次 tick 的时长
+const TICK: Duration = Duration::from_millis(10);
+
+/// 一个待触发的定时器
+struct Timer<T> {
+    id: u64,
+    delay: Duration,
+    rounds: u64,
+    payload: T,
+}
+
+struct Inner<T> {
+    slots: Vec<Mutex<Vec<Timer<T>>>>,
+    // 记录每个定时器当前所在的槽位，便于 cancel/refresh 定位
+    locations: Mutex<HashMap<u64, usize>>,
+    current: AtomicUsize,
+    next_id: AtomicU64,
+    sender: Sender<T>,
+}
+
+/// 哈希时间轮：用固定数量的槽位 + 每 tick 推进一格的方式调度延迟任务，
+/// 避免为了实现一个短暂的防抖而忙轮询。
+pub struct TimingWheel<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T: Send + 'static> TimingWheel<T> {
+    /// 创建一个新的时间轮，并返回接收到期任务的接收端
+    pub fn new() -> (Self, Receiver<T>) {
+        let (sender, receiver) = mpsc::channel();
+
+        let inner = Arc::new(Inner {
+            slots: (0..SLOT_COUNT).map(|_| Mutex::new(Vec::new())).collect(),
+            locations: Mutex::new(HashMap::new()),
+            current: AtomicUsize::new(0),
+            next_id: AtomicU64::new(0),
+            sender,
+        });
+
+        let tick_inner = inner.clone();
+        thread::spawn(move || tick_loop(tick_inner));
+
+        (TimingWheel { inner }, receiver)
+    }
+
+    /// 添加一个在 `delay` 后触发的定时器，返回其 id
+    pub fn add(&self, delay: Duration, payload: T) -> u64 {
+        let id = self.inner.next_id.fetch_add(1, Ordering::SeqCst);
+        self.schedule(id, delay, payload);
+        id
+    }
+
+    /// 取消一个尚未触发的定时器
+    #[allow(dead_code)]
+    pub fn cancel(&self, id: u64) {
+        // 先释放 `locations` 的锁再去拿槽位锁，与 tick_loop 的加锁顺序保持一致，避免互相等待造成死锁
+        let Some(slot) = self.inner.locations.lock().unwrap().remove(&id) else {
+            return;
+        };
+        self.inner.slots[slot]
+            .lock()
+            .unwrap()
+            .retain(|timer| timer.id != id);
+    }
+
+    /// 重置一个已存在的定时器的到期时间（沿用其原始延迟），
+    /// 用于把短时间内多次触发的事件合并为一次。
+    /// 若定时器已经触发或不存在，返回 `false`，调用方应自行重新 `add` 一个定时器
+    pub fn refresh(&self, id: u64) -> bool {
+        let Some(slot) = self.inner.locations.lock().unwrap().remove(&id) else {
+            return false;
+        };
+
+        let mut timers = self.inner.slots[slot].lock().unwrap();
+        let Some(pos) = timers.iter().position(|timer| timer.id == id) else {
+            return false;
+        };
+        let timer = timers.remove(pos);
+        drop(timers);
+
+        self.schedule(id, timer.delay, timer.payload);
+        true
+    }
+
+    fn schedule(&self, id: u64, delay: Duration, payload: T) {
+        let ticks = (delay.as_millis() / TICK.as_millis()).max(1) as usize;
+        let current = self.inner.current.load(Ordering::SeqCst);
+        let slot = (current + ticks) % SLOT_COUNT;
+        // ticks 为 SLOT_COUNT 整数倍时槽位恰好是 current，第一次经过就已是满一圈，故按 ticks - 1 计圈数
+        let rounds = ((ticks - 1) / SLOT_COUNT) as u64;
+
+        // 必须先插入 locations 再把定时器放进槽位：如果顺序反过来，
+        // tick_loop 有可能在两步之间就把刚插入槽位的定时器触发并移除，
+        // 它在 locations 里找不到对应记录，随后这里的插入就会留下一条
+        // 永远没人清理的孤儿记录（指向一个已经触发过的 id）
+        self.inner.locations.lock().unwrap().insert(id, slot);
+        self.inner.slots[slot].lock().unwrap().push(Timer {
+            id,
+            delay,
+            rounds,
+            payload,
+        });
+    }
+}
+
+/// 由独立线程驱动，每个 tick 推进一格并触发到期的定时器
+fn tick_loop<T: Send + 'static>(inner: Arc<Inner<T>>) {
+    loop {
+        thread::sleep(TICK);
+
+        let slot = (inner.current.load(Ordering::SeqCst) + 1) % SLOT_COUNT;
+        inner.current.store(slot, Ordering::SeqCst);
+
+        let mut timers = inner.slots[slot].lock().unwrap();
+        let mut i = 0;
+        while i < timers.len() {
+            if timers[i].rounds == 0 {
+                let timer = timers.remove(i);
+                inner.locations.lock().unwrap().remove(&timer.id);
+                if inner.sender.send(timer.payload).is_err() {
+                    // 接收端已断开，时间轮不再有使用者
+                    return;
+                }
+            } else {
+                timers[i].rounds -= 1;
+                i += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn test_fires_after_delay() {
+        let (wheel, rx) = TimingWheel::new();
+        let start = Instant::now();
+
+        wheel.add(Duration::from_millis(30), "fired");
+
+        let result = rx.recv_timeout(Duration::from_millis(300)).unwrap();
+        assert_eq!(result, "fired");
+        assert!(start.elapsed() >= Duration::from_millis(30));
+    }
+
+    #[test]
+    fn test_refresh_postpones_expiry_and_coalesces() {
+        let (wheel, rx) = TimingWheel::new();
+        let id = wheel.add(Duration::from_millis(50), "event");
+
+        thread::sleep(Duration::from_millis(30));
+        assert!(wheel.refresh(id));
+
+        // 刷新把到期时间从这一刻起又往后推了 50ms，所以原本的到期时刻不该触发
+        thread::sleep(Duration::from_millis(30));
+        assert!(rx.try_recv().is_err());
+
+        let result = rx.recv_timeout(Duration::from_millis(300)).unwrap();
+        assert_eq!(result, "event");
+    }
+
+    #[test]
+    fn test_refresh_on_unknown_id_returns_false() {
+        let (wheel, _rx) = TimingWheel::<()>::new();
+        assert!(!wheel.refresh(999));
+    }
+
+    #[test]
+    fn test_cancel_prevents_firing() {
+        let (wheel, rx) = TimingWheel::new();
+        let id = wheel.add(Duration::from_millis(20), "event");
+        wheel.cancel(id);
+
+        assert!(rx.recv_timeout(Duration::from_millis(150)).is_err());
+    }
+
+    #[test]
+    fn test_rounds_wrap_around_slot_count() {
+        let (wheel, rx) = TimingWheel::new();
+        let delay = TICK * (SLOT_COUNT as u32 + 5);
+        let start = Instant::now();
+
+        wheel.add(delay, "wrapped");
+
+        let result = rx.recv_timeout(delay + Duration::from_millis(500)).unwrap();
+        assert_eq!(result, "wrapped");
+        assert!(start.elapsed() >= delay);
+    }
+
+    #[test]
+    fn test_concurrent_add_refresh_cancel_do_not_deadlock() {
+        let (wheel, _rx) = TimingWheel::new();
+        let wheel = Arc::new(wheel);
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let wheel = wheel.clone();
+                thread::spawn(move || {
+                    for _ in 0..50 {
+                        let id = wheel.add(Duration::from_millis(5), ());
+                        wheel.refresh(id);
+                        wheel.cancel(id);
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+}